@@ -1,8 +1,23 @@
 //! A Rust package that provides utility functions inspired by or ported from other programming languages.
-//! 
+//!
 //! rusty_utils aims to simplify intricate and hard-to-read instructions in Rust, allowing you to express complex operations with clarity and brevity.
 //! Inspired by the conciseness of other programming languages, rusty_utils provides a set of functions that compact multiple steps into a single, readable call.
+//!
+//! # Feature flags
+//!
+//! - `std` (default) - Uses the standard library.
+//! - `alloc` - Builds against `alloc` instead of `std`, for `#![no_std]` targets that still have a heap. Either `std` or `alloc` is required for every function that returns `Vec`/`String`; without one of them those functions are compiled out.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{string::String, vec, vec::Vec};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Compacts the standard ` if (condition) {} else {} ` into a single call, to improve readability and please users accustomed  to the ternary operator in other languages.
 /// 
@@ -106,6 +121,11 @@ pub fn ternary_operator <T> (condition: bool, if_true: T, if_false: T) -> T {
 /// Compacts the standar `string_value.chars().rev().collect()` into a single call, to improve
 /// readability.
 ///
+/// This reverses by Unicode scalar value (`char`), which is cheap but splits multi-codepoint
+/// grapheme clusters (combining marks, modified emoji, ...) apart from the base they belong to.
+/// Use [`reverse_string_with_mode`] with [`ReverseMode::Grapheme`] when the input may contain
+/// those and the user-perceived characters must stay intact.
+///
 /// # Arguments
 ///
 /// - `string_value` - The string to be reversed by the function.
@@ -124,7 +144,7 @@ pub fn ternary_operator <T> (condition: bool, if_true: T, if_false: T) -> T {
 ///
 ///      ```rust
 ///      use rusty_utils::reverse_string;
-///     
+///
 ///      let mut input: &str = "Hello, World";
 ///
 ///      //It's not necesary to trim the string, since it will work regardless,
@@ -136,10 +156,64 @@ pub fn ternary_operator <T> (condition: bool, if_true: T, if_false: T) -> T {
 ///      ```
 /// <br>
 
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub fn reverse_string (string_value: &str) -> String {
     string_value.chars().rev().collect()
 }
 
+/// Selects the unit [`reverse_string_with_mode`] reverses by.
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseMode {
+    /// Reverses by Unicode scalar value (`char`). Cheap, but splits extended grapheme clusters
+    /// apart from the base codepoint they belong to.
+    Char,
+    /// Reverses by extended grapheme cluster, so user-perceived characters made of several
+    /// codepoints (combining marks, modified emoji, ...) stay intact.
+    Grapheme,
+}
+
+/// Reverses `string_value` using the unit selected by `mode`, to improve readability when the
+/// caller needs to opt into grapheme-safe reversal instead of the cheaper [`reverse_string`]
+/// codepoint behavior.
+///
+/// # Arguments
+///
+/// - `string_value` - The string to be reversed by the function.
+/// - `mode` - Whether to reverse by `char` or by extended grapheme cluster.
+///
+/// # Returns
+///
+/// The reversed input string.
+///
+/// # Panics
+///
+/// This function does not panic under normal circumstances.
+///
+/// # Examples
+///
+/// - Reversing a string containing a combining accent without moving it onto the wrong base:
+///
+///      ```rust
+///      use rusty_utils::{reverse_string_with_mode, ReverseMode};
+///
+///      // "ba\u{0301}" is "b" followed by "a" + combining acute accent (U+0301),
+///      // i.e. a single grapheme cluster "á" preceded by "b".
+///      let input = "ba\u{0301}";
+///
+///      let reversed = reverse_string_with_mode(input, ReverseMode::Grapheme);
+///      assert_eq!(reversed, "a\u{0301}b");
+///      ```
+/// <br>
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn reverse_string_with_mode (string_value: &str, mode: ReverseMode) -> String {
+    match mode {
+        ReverseMode::Char => string_value.chars().rev().collect(),
+        ReverseMode::Grapheme => string_value.graphemes(true).rev().collect(),
+    }
+}
+
 /// Allows the user to concatenate multiple arrays without having to worry about array sizing.
 ///
 /// # Arguments
@@ -177,6 +251,7 @@ pub fn reverse_string (string_value: &str) -> String {
 ///      ```
 /// <br>
 
+#[cfg(any(feature = "std", feature = "alloc"))]
 pub fn concat_arrays <T: Copy> (arrays: Vec<&[T]>) -> Vec<T> {
     let vector_size: usize = arrays.iter().map(|arr| arr.len()).sum();
     let mut result_vector: Vec<T> = Vec::with_capacity(vector_size);
@@ -184,10 +259,354 @@ pub fn concat_arrays <T: Copy> (arrays: Vec<&[T]>) -> Vec<T> {
     for arr in arrays {
         result_vector.extend_from_slice(arr);
     }
-    
+
     result_vector
 }
 
+/// Compacts Python's `itertools.combinations` into a single call, yielding every `r`-length
+/// selection of `items` in lexicographic index order.
+///
+/// # Arguments
+///
+/// - `items` - The slice to draw selections from.
+/// - `r` - The size of each selection.
+///
+/// # Returns
+///
+/// A vector containing every `r`-length combination of `items`, in lexicographic index order.
+/// If `r` is greater than `items.len()`, the result is empty. If `r` is `0`, the result contains
+/// a single empty selection.
+///
+/// # Panics
+///
+/// This function does not panic under normal circumstances.
+///
+/// # Examples
+///
+/// - Selecting every pair from a list:
+///
+///      ```rust
+///      use rusty_utils::combinations;
+///
+///      let items: [i32; 4] = [1, 2, 3, 4];
+///      let result = combinations(&items, 2);
+///
+///      assert_eq!(result, vec![
+///          vec![1, 2], vec![1, 3], vec![1, 4],
+///          vec![2, 3], vec![2, 4],
+///          vec![3, 4],
+///      ]);
+///      ```
+/// <br>
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn combinations <T: Clone> (items: &[T], r: usize) -> Vec<Vec<T>> {
+    let item_count: usize = items.len();
+
+    if r > item_count {
+        return Vec::new();
+    }
+
+    if r == 0 {
+        return vec![Vec::new()];
+    }
+
+    let mut indices: Vec<usize> = (0..r).collect();
+    let mut result: Vec<Vec<T>> = vec![indices.iter().map(|&i| items[i].clone()).collect()];
+
+    loop {
+        let mut i: usize = r;
+        let found_index = loop {
+            if i == 0 {
+                break None;
+            }
+
+            i -= 1;
+
+            if indices[i] != i + item_count - r {
+                break Some(i);
+            }
+        };
+
+        let i = match found_index {
+            Some(i) => i,
+            None => return result,
+        };
+
+        indices[i] += 1;
+        for j in (i + 1)..r {
+            indices[j] = indices[j - 1] + 1;
+        }
+
+        result.push(indices.iter().map(|&i| items[i].clone()).collect());
+    }
+}
+
+/// Compacts Python's `itertools.permutations` into a single call, yielding every `r`-length
+/// ordered arrangement of `items` in lexicographic index order.
+///
+/// # Arguments
+///
+/// - `items` - The slice to draw arrangements from.
+/// - `r` - The size of each arrangement.
+///
+/// # Returns
+///
+/// A vector containing every `r`-length permutation of `items`, in lexicographic index order.
+/// If `r` is greater than `items.len()`, the result is empty. If `r` is `0`, the result contains
+/// a single empty arrangement.
+///
+/// # Panics
+///
+/// This function does not panic under normal circumstances.
+///
+/// # Examples
+///
+/// - Arranging 2 items out of 3:
+///
+///      ```rust
+///      use rusty_utils::permutations;
+///
+///      let items: [i32; 3] = [1, 2, 3];
+///      let result = permutations(&items, 2);
+///
+///      assert_eq!(result, vec![
+///          vec![1, 2], vec![1, 3],
+///          vec![2, 1], vec![2, 3],
+///          vec![3, 1], vec![3, 2],
+///      ]);
+///      ```
+/// <br>
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn permutations <T: Clone> (items: &[T], r: usize) -> Vec<Vec<T>> {
+    let item_count: usize = items.len();
+
+    if r > item_count {
+        return Vec::new();
+    }
+
+    if r == 0 {
+        return vec![Vec::new()];
+    }
+
+    let mut indices: Vec<usize> = (0..item_count).collect();
+    let mut cycles: Vec<usize> = (item_count - r + 1..=item_count).rev().collect();
+    let mut result: Vec<Vec<T>> = vec![indices[..r].iter().map(|&i| items[i].clone()).collect()];
+
+    'outer: loop {
+        for i in (0..r).rev() {
+            cycles[i] -= 1;
+
+            if cycles[i] == 0 {
+                indices[i..].rotate_left(1);
+                cycles[i] = item_count - i;
+            } else {
+                let swap_with: usize = item_count - cycles[i];
+                indices.swap(i, swap_with);
+                result.push(indices[..r].iter().map(|&i| items[i].clone()).collect());
+                continue 'outer;
+            }
+        }
+
+        return result;
+    }
+}
+
+/// Compacts Python's `range(start, end, step)` into a single call, materializing a numeric
+/// sequence instead of requiring a manual `while` loop.
+///
+/// # Arguments
+///
+/// - `start` - The first value of the sequence.
+/// - `end` - The exclusive bound of the sequence.
+/// - `step` - The amount added to the previous value to produce the next one. May be negative
+///   to produce a descending sequence.
+///
+/// # Returns
+///
+/// A vector with `start, start + step, start + 2 * step, ...`, stopping before `end` is reached
+/// or passed. For a positive `step` the sequence climbs while strictly less than `end`; for a
+/// negative `step` it descends while strictly greater than `end`. A `step` of zero returns an
+/// empty vector rather than looping forever.
+///
+/// # Panics
+///
+/// This function does not panic under normal circumstances.
+///
+/// # Examples
+///
+/// - Building an ascending sequence:
+///
+///      ```rust
+///      use rusty_utils::range_step;
+///
+///      let result = range_step(0, 10, 2);
+///      assert_eq!(result, vec![0, 2, 4, 6, 8]);
+///      ```
+/// <br>
+///
+/// - Building a descending sequence:
+///
+///      ```rust
+///      use rusty_utils::range_step;
+///
+///      let result = range_step(10, 0, -2);
+///      assert_eq!(result, vec![10, 8, 6, 4, 2]);
+///      ```
+/// <br>
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn range_step <T> (start: T, end: T, step: T) -> Vec<T>
+where
+    T: Copy + PartialOrd + Default + StepAdd,
+{
+    let zero: T = T::default();
+
+    if step == zero {
+        return Vec::new();
+    }
+
+    let mut result: Vec<T> = Vec::new();
+    let mut current: T = start;
+
+    if step > zero {
+        while current < end {
+            result.push(current);
+            current = match current.step_add(step) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    } else {
+        while current > end {
+            result.push(current);
+            current = match current.step_add(step) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+
+    result
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod private {
+    pub trait Sealed {}
+}
+
+/// Adds a step the way [`range_step`] needs to: checked for integer types, so a sequence whose
+/// last computed-but-unused value would overflow simply stops instead of panicking, and
+/// unchecked for float types, which already saturate instead of panicking on overflow.
+///
+/// Sealed: only implemented for the primitive integer and float types `range_step` supports.
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub trait StepAdd: Sized + private::Sealed {
+    fn step_add(self, rhs: Self) -> Option<Self>;
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+macro_rules! impl_step_add_checked {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl StepAdd for $t {
+                fn step_add(self, rhs: Self) -> Option<Self> {
+                    self.checked_add(rhs)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+macro_rules! impl_step_add_unchecked {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl StepAdd for $t {
+                fn step_add(self, rhs: Self) -> Option<Self> {
+                    Some(self + rhs)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl_step_add_checked!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl_step_add_unchecked!(f32, f64);
+
+/// Compacts Ruby's `n.times { ... }` idiom into a single call, invoking `body` once per index
+/// in `0..count` without requiring a manual `for` loop.
+///
+/// # Arguments
+///
+/// - `count` - The number of times `body` is invoked.
+/// - `body` - The closure invoked with each iteration index.
+///
+/// # Panics
+///
+/// This function does not panic under normal circumstances.
+///
+/// # Examples
+///
+/// - Collecting each iteration index:
+///
+///      ```rust
+///      use rusty_utils::times;
+///
+///      let mut seen: Vec<usize> = Vec::new();
+///      times(3, |i| seen.push(i));
+///
+///      assert_eq!(seen, vec![0, 1, 2]);
+///      ```
+/// <br>
+pub fn times <F: FnMut(usize)> (count: usize, mut body: F) {
+    for i in 0..count {
+        body(i);
+    }
+}
+
+/// Compacts `(0..count).map(...).collect()` into a single call, building a vector of `count`
+/// values from the closure's return at each index.
+///
+/// # Arguments
+///
+/// - `count` - The number of values to generate.
+/// - `f` - The closure invoked with each iteration index, whose return value is collected.
+///
+/// # Returns
+///
+/// A vector containing the result of calling `f` once for every index in `0..count`.
+///
+/// # Panics
+///
+/// This function does not panic under normal circumstances.
+///
+/// # Examples
+///
+/// - Building a vector of squares:
+///
+///      ```rust
+///      use rusty_utils::times_map;
+///
+///      let result = times_map(5, |i| i * i);
+///      assert_eq!(result, vec![0, 1, 4, 9, 16]);
+///      ```
+/// <br>
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn times_map <T, F: FnMut(usize) -> T> (count: usize, mut f: F) -> Vec<T> {
+    let mut result: Vec<T> = Vec::with_capacity(count);
+
+    for i in 0..count {
+        result.push(f(i));
+    }
+
+    result
+}
+
 //? ===========
 //? = [Tests] =
 //? ===========
@@ -288,6 +707,46 @@ mod reverse_string_tests {
 
 #[cfg(test)]
 
+//? --------------------------------------
+//? - [Tests] - Reverse String With Mode -
+//? --------------------------------------
+
+mod reverse_string_with_mode_tests {
+    use super::*;
+
+    //Test Char mode matches the plain reverse_string behavior
+    #[test]
+    fn reverse_string_with_mode_char() {
+        let result = reverse_string_with_mode("test phrase", ReverseMode::Char);
+        assert_eq!(result, "esarhp tset");
+    }
+
+    //Test Char mode splits a combining accent from its base, corrupting the grapheme
+    #[test]
+    fn reverse_string_with_mode_char_splits_combining_marks() {
+        let input = "ba\u{0301}";
+        let result = reverse_string_with_mode(input, ReverseMode::Char);
+        assert_eq!(result, "\u{0301}ab");
+    }
+
+    //Test Grapheme mode keeps a combining accent attached to its base
+    #[test]
+    fn reverse_string_with_mode_grapheme_keeps_combining_marks() {
+        let input = "ba\u{0301}";
+        let result = reverse_string_with_mode(input, ReverseMode::Grapheme);
+        assert_eq!(result, "a\u{0301}b");
+    }
+
+    //Test Grapheme mode on ASCII input matches the plain reverse_string behavior
+    #[test]
+    fn reverse_string_with_mode_grapheme_ascii() {
+        let result = reverse_string_with_mode("test phrase", ReverseMode::Grapheme);
+        assert_eq!(result, "esarhp tset");
+    }
+}
+
+#[cfg(test)]
+
 //? ---------------------------
 //? - [Tests] - Concat Arrays -
 //? ---------------------------
@@ -315,3 +774,168 @@ mod concat_array_tests {
         assert_eq!(vector_result, [1, 2, 3, 4, 5, 6, 7, 8]);
     }
 }
+
+#[cfg(test)]
+
+//? --------------------------
+//? - [Tests] - Combinations -
+//? --------------------------
+
+mod combinations_tests {
+    use super::*;
+
+    //Test combinations with a typical r
+    #[test]
+    fn combinations_pairs() {
+        let items: [i32; 4] = [1, 2, 3, 4];
+        let result = combinations(&items, 2);
+        assert_eq!(result, vec![
+            vec![1, 2], vec![1, 3], vec![1, 4],
+            vec![2, 3], vec![2, 4],
+            vec![3, 4],
+        ]);
+    }
+
+    //Test r == 0 yields a single empty selection
+    #[test]
+    fn combinations_zero_r() {
+        let items: [i32; 3] = [1, 2, 3];
+        let result = combinations(&items, 0);
+        assert_eq!(result, vec![Vec::<i32>::new()]);
+    }
+
+    //Test r greater than the item count yields nothing
+    #[test]
+    fn combinations_r_too_large() {
+        let items: [i32; 2] = [1, 2];
+        let result = combinations(&items, 3);
+        assert_eq!(result, Vec::<Vec<i32>>::new());
+    }
+}
+
+#[cfg(test)]
+
+//? --------------------------
+//? - [Tests] - Permutations -
+//? --------------------------
+
+mod permutations_tests {
+    use super::*;
+
+    //Test permutations with a typical r
+    #[test]
+    fn permutations_pairs() {
+        let items: [i32; 3] = [1, 2, 3];
+        let result = permutations(&items, 2);
+        assert_eq!(result, vec![
+            vec![1, 2], vec![1, 3],
+            vec![2, 1], vec![2, 3],
+            vec![3, 1], vec![3, 2],
+        ]);
+    }
+
+    //Test r == 0 yields a single empty arrangement
+    #[test]
+    fn permutations_zero_r() {
+        let items: [i32; 3] = [1, 2, 3];
+        let result = permutations(&items, 0);
+        assert_eq!(result, vec![Vec::<i32>::new()]);
+    }
+
+    //Test r greater than the item count yields nothing
+    #[test]
+    fn permutations_r_too_large() {
+        let items: [i32; 2] = [1, 2];
+        let result = permutations(&items, 3);
+        assert_eq!(result, Vec::<Vec<i32>>::new());
+    }
+}
+
+#[cfg(test)]
+
+//? -------------------------
+//? - [Tests] - Range Step -
+//? -------------------------
+
+mod range_step_tests {
+    use super::*;
+
+    //Test an ascending integer sequence
+    #[test]
+    fn range_step_ascending() {
+        let result = range_step(0, 10, 2);
+        assert_eq!(result, vec![0, 2, 4, 6, 8]);
+    }
+
+    //Test a descending integer sequence
+    #[test]
+    fn range_step_descending() {
+        let result = range_step(10, 0, -2);
+        assert_eq!(result, vec![10, 8, 6, 4, 2]);
+    }
+
+    //Test a zero step returns an empty vector
+    #[test]
+    fn range_step_zero_step() {
+        let result = range_step(0, 10, 0);
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    //Test a floating point sequence
+    #[test]
+    fn range_step_floats() {
+        let result = range_step(0.0, 1.0, 0.25);
+        assert_eq!(result, vec![0.0, 0.25, 0.5, 0.75]);
+    }
+
+    //Test that a step landing on the last in-range value doesn't panic even though the
+    //following (unused) step would overflow the integer type
+    #[test]
+    fn range_step_stops_before_overflowing() {
+        let result = range_step(i32::MAX - 1, i32::MAX, 5);
+        assert_eq!(result, vec![i32::MAX - 1]);
+
+        let result = range_step(i32::MIN + 1, i32::MIN, -5);
+        assert_eq!(result, vec![i32::MIN + 1]);
+    }
+}
+
+#[cfg(test)]
+
+//? --------------------
+//? - [Tests] - Times -
+//? --------------------
+
+mod times_tests {
+    use super::*;
+
+    //Test times invokes body once per index
+    #[test]
+    fn times_collects_indices() {
+        let mut seen: Vec<usize> = Vec::new();
+        times(3, |i| seen.push(i));
+        assert_eq!(seen, vec![0, 1, 2]);
+    }
+
+    //Test times with a count of zero invokes nothing
+    #[test]
+    fn times_zero_count() {
+        let mut seen: Vec<usize> = Vec::new();
+        times(0, |i| seen.push(i));
+        assert_eq!(seen, Vec::<usize>::new());
+    }
+
+    //Test times_map collects each closure's return value
+    #[test]
+    fn times_map_collects_values() {
+        let result = times_map(5, |i| i * i);
+        assert_eq!(result, vec![0, 1, 4, 9, 16]);
+    }
+
+    //Test times_map with a count of zero yields an empty vector
+    #[test]
+    fn times_map_zero_count() {
+        let result = times_map(0, |i| i * i);
+        assert_eq!(result, Vec::<usize>::new());
+    }
+}